@@ -0,0 +1,36 @@
+// src/api.rs
+use anyhow::Result;
+use reqwest::Client;
+use serde_json::Value;
+
+const ANILIST_API_URL: &str = "https://graphql.anilist.co";
+
+/// Thin wrapper around the AniList GraphQL API
+pub struct AniListClient {
+    client: Client,
+}
+
+impl AniListClient {
+    /// Create a new AniList API client
+    pub fn new() -> Self {
+        Self {
+            client: Client::new(),
+        }
+    }
+
+    /// Execute a GraphQL query against the AniList API
+    pub async fn query(&self, query: &str, variables: Value) -> Result<Value> {
+        let response = self
+            .client
+            .post(ANILIST_API_URL)
+            .json(&serde_json::json!({
+                "query": query,
+                "variables": variables,
+            }))
+            .send()
+            .await?;
+
+        let body: Value = response.json().await?;
+        Ok(body)
+    }
+}
@@ -0,0 +1,31 @@
+// src/commands/mod.rs
+mod schedule;
+mod week;
+
+pub use schedule::ScheduleCommand;
+pub use week::WeekCommand;
+
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+/// Wall-clock facts threaded through every command so time-dependent logic
+/// (relative times, day-of-week ranges) can be tested with a frozen instant
+/// instead of reaching for the live system clock.
+#[derive(Clone, Copy, Debug)]
+pub struct Context {
+    pub now: DateTime<Utc>,
+}
+
+impl Context {
+    /// Capture the current instant as the command's `now`
+    pub fn now() -> Self {
+        Self { now: Utc::now() }
+    }
+}
+
+/// Shared behaviour for all CLI subcommands
+#[async_trait]
+pub trait Command {
+    async fn execute(&self, ctx: &Context) -> Result<()>;
+}
@@ -1,14 +1,15 @@
 use anyhow::Result;
 use async_trait::async_trait;
-use chrono::{Datelike, FixedOffset, TimeZone, Utc, Duration};
-use prettytable::{color, Row};
+use chrono::{Datelike, DateTime, FixedOffset, TimeZone, Utc, Duration};
+use regex::Regex;
 use serde_json::Value;
+use std::io::{stdout, IsTerminal};
 
 use crate::{
     api::AniListClient,
-    commands::Command,
-    display::{create_table, format_datetime, styled_cell},
-    utils::{get_user_timezone, parse_day_of_week, match_timezone},
+    commands::{Command, Context},
+    display::{format_datetime, Format, ScheduleRow},
+    utils::{get_user_timezone, local_to_utc_checked, match_iana_timezone, match_timezone, parse_day_of_week, resolve_offset_at},
 };
 
 /// Command to show upcoming anime airing schedule
@@ -16,24 +17,38 @@ pub struct ScheduleCommand {
     day: Option<String>,
     interval: u32,
     timezone: Option<String>,
+    format: Format,
+    grep: Option<Regex>,
     client: AniListClient,
 }
 
 impl ScheduleCommand {
     /// Create a new schedule command
-    pub fn new(day: Option<String>, interval: u32, timezone: Option<String>) -> Self {
+    pub fn new(
+        day: Option<String>,
+        interval: u32,
+        timezone: Option<String>,
+        format: Format,
+        grep: Option<Regex>,
+    ) -> Self {
         Self {
             day,
             interval,
             timezone,
+            format,
+            grep,
             client: AniListClient::new(),
         }
     }
 
-    /// Get the timezone to use for display
-    fn get_timezone(&self) -> FixedOffset {
+    /// Get the timezone to use for display, resolved to the offset in
+    /// effect at `now` (so a DST-observing IANA zone gets its offset for
+    /// `now`'s date rather than a fixed standard-time offset). `execute`
+    /// renders every row with this one offset, so a row airing after a DST
+    /// transition later in the window is shown in the pre-transition offset.
+    fn get_timezone(&self, now: DateTime<Utc>) -> FixedOffset {
         if let Some(tz) = &self.timezone {
-            match_timezone(&tz).unwrap_or_else(|| {
+            resolve_offset_at(tz, now).unwrap_or_else(|| {
                 eprintln!("Invalid timezone: {}. Using default timezone.", tz);
                 get_user_timezone()
             })
@@ -43,44 +58,79 @@ impl ScheduleCommand {
     }
 
     /// Get the day to show schedule for
-    fn get_target_day(&self) -> u32 {
+    fn get_target_day(&self, now: DateTime<Utc>) -> u32 {
         if let Some(day) = &self.day {
-            parse_day_of_week(day).unwrap_or(Utc::now().weekday().num_days_from_monday())
+            parse_day_of_week(day).unwrap_or(now.weekday().num_days_from_monday())
         } else {
-            Utc::now().weekday().num_days_from_monday()
+            now.weekday().num_days_from_monday()
         }
     }
 
     /// Get the time range for the schedule
-    fn get_time_range(&self) -> (i64, i64) {
-        let timezone = self.get_timezone();
-        let now_utc = Utc::now();
-        let now_local = now_utc.with_timezone(&timezone);
-        
-        let target_day = self.get_target_day();
-        let current_day = now_local.weekday().num_days_from_monday();
-        let days_diff = if target_day > current_day {
-            target_day - current_day
+    ///
+    /// When `self.timezone` names a real IANA zone (e.g. "America/New_York")
+    /// the target day's start is built from a local wall-clock time and
+    /// converted back to UTC via [`local_to_utc_checked`], so the DST gap
+    /// and overlap cases are handled instead of silently mis-rendering.
+    /// Abbreviations and the default timezone stay on the simpler
+    /// fixed-offset arithmetic, since a `FixedOffset` has no DST to get
+    /// wrong in the first place.
+    ///
+    /// The IANA branch is only taken when `self.timezone` does *not* match
+    /// the abbreviation table, mirroring [`resolve_offset_at`]'s
+    /// abbreviation-first precedence exactly — otherwise an abbreviation
+    /// `chrono-tz` also happens to parse (e.g. "CET", which `chrono-tz`
+    /// treats as DST-observing while [`match_timezone`] treats as a
+    /// permanent fixed offset) could get queried here on one offset while
+    /// [`get_timezone`] renders it on another, an hour apart during DST.
+    fn get_time_range(&self, now: DateTime<Utc>) -> (i64, i64) {
+        let target_day = self.get_target_day(now);
+
+        let iana_zone = self
+            .timezone
+            .as_deref()
+            .filter(|tz| match_timezone(tz).is_none())
+            .and_then(match_iana_timezone);
+
+        let start = if let Some(zone) = iana_zone {
+            let now_local = now.with_timezone(&zone);
+            let current_day = now_local.weekday().num_days_from_monday();
+            let days_diff = if target_day > current_day {
+                target_day - current_day
+            } else {
+                0
+            };
+
+            let target_date = now_local.date_naive() + Duration::days(days_diff as i64);
+            local_to_utc_checked(target_date.and_time(now_local.time()), &zone).timestamp()
         } else {
-            0
+            let timezone = self.get_timezone(now);
+            let now_local = now.with_timezone(&timezone);
+            let current_day = now_local.weekday().num_days_from_monday();
+            let days_diff = if target_day > current_day {
+                target_day - current_day
+            } else {
+                0
+            };
+
+            now_local.timestamp() + ((days_diff as i64) * 24 * 3600)
         };
-        
-        let start = now_local.timestamp() + ((days_diff as i64) * 24 * 3600);
+
         let end = start + ((self.interval as i64) * 24 * 3600);
-        
+
         (start, end)
     }
 
     /// Format relative time (e.g., "2h ago", "in 3h")
-    fn format_relative_time(&self, airing_at: i64) -> String {
-        let now = Utc::now().timestamp();
+    fn format_relative_time(&self, airing_at: i64, now: DateTime<Utc>) -> String {
+        let now = now.timestamp();
         let diff = airing_at - now;
         let duration = Duration::seconds(diff);
 
         if diff < 0 {
             // Past time
             let abs_duration = Duration::seconds(-diff);
-            if abs_duration.num_hours() > 24 {
+            if abs_duration.num_hours() >= 24 {
                 format!("{}d ago", abs_duration.num_days())
             } else if abs_duration.num_hours() > 0 {
                 format!("{}h ago", abs_duration.num_hours())
@@ -91,7 +141,7 @@ impl ScheduleCommand {
             }
         } else {
             // Future time
-            if duration.num_hours() > 24 {
+            if duration.num_hours() >= 24 {
                 format!("in {}d", duration.num_days())
             } else if duration.num_hours() > 0 {
                 format!("in {}h", duration.num_hours())
@@ -106,9 +156,9 @@ impl ScheduleCommand {
 
 #[async_trait]
 impl Command for ScheduleCommand {
-    async fn execute(&self) -> Result<()> {
-        let timezone = self.get_timezone();
-        let (start, end) = self.get_time_range();
+    async fn execute(&self, ctx: &Context) -> Result<()> {
+        let timezone = self.get_timezone(ctx.now);
+        let (start, end) = self.get_time_range(ctx.now);
 
         // Get timezone name for display
         let tz_name = if let Some(tz) = &self.timezone {
@@ -147,31 +197,39 @@ impl Command for ScheduleCommand {
         let response: Value = self.client.query(query, variables).await?;
         let schedules = response["data"]["Page"]["airingSchedules"].as_array().unwrap();
 
-        // Create and populate table with timezone header
-        let mut table = create_table(&[&format!("Schedule ({})", tz_name), "Episode", "Time", "Status"]);
-        
+        let mut rows = Vec::with_capacity(schedules.len());
         for schedule in schedules {
             let title = schedule["media"]["title"]["english"]
                 .as_str()
                 .or(schedule["media"]["title"]["romaji"].as_str())
                 .unwrap_or("Unknown Title");
-            
+
+            if let Some(grep) = &self.grep {
+                if !grep.is_match(title) {
+                    continue;
+                }
+            }
+
             let episode: i64 = schedule["episode"].as_i64().unwrap_or(0);
             let airing_at: i64 = schedule["airingAt"].as_i64().unwrap_or(0);
-            
+
             let airing_time = Utc.timestamp_opt(airing_at, 0).unwrap();
-            let formatted_time = format_datetime(airing_time, timezone);
-            let relative_time = self.format_relative_time(airing_at);
-
-            table.add_row(Row::new(vec![
-                styled_cell(title, color::CYAN),
-                styled_cell(&episode.to_string(), color::YELLOW),
-                styled_cell(&formatted_time, color::GREEN),
-                styled_cell(&relative_time, if airing_at < Utc::now().timestamp() { color::RED } else { color::BLUE }),
-            ]));
+            let local_time = airing_time.with_timezone(&timezone);
+
+            rows.push(ScheduleRow {
+                title: title.to_string(),
+                episode,
+                date: local_time.date_naive(),
+                time: format_datetime(airing_time, timezone),
+                relative: self.format_relative_time(airing_at, ctx.now),
+                is_past: airing_at < ctx.now.timestamp(),
+            });
         }
 
-        table.printstd();
+        // `out` below really is the process's real stdout, so it's safe to
+        // tell the formatter it may route colour through `print_tty`.
+        let is_tty = stdout().is_terminal();
+        self.format.formatter().write(&rows, &tz_name, &mut stdout(), is_tty)?;
         Ok(())
     }
 }
@@ -180,67 +238,121 @@ impl Command for ScheduleCommand {
 mod tests {
     use super::*;
 
+    /// A fixed instant (Monday, 2024-01-01 12:00:00 UTC) used so time-dependent
+    /// assertions don't depend on when the test suite happens to run.
+    fn frozen_now() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2024, 1, 1, 12, 0, 0).unwrap()
+    }
+
     #[tokio::test]
     async fn test_schedule_command_today() {
-        let command = ScheduleCommand::new(None, 2, None);
-        assert!(command.execute().await.is_ok());
+        let command = ScheduleCommand::new(None, 2, None, Format::Table, None);
+        let ctx = Context { now: frozen_now() };
+        assert!(command.execute(&ctx).await.is_ok());
     }
 
     #[tokio::test]
     async fn test_schedule_command_specific_day() {
-        let command = ScheduleCommand::new(Some("monday".to_string()), 2, None);
-        assert!(command.execute().await.is_ok());
+        let command = ScheduleCommand::new(Some("monday".to_string()), 2, None, Format::Table, None);
+        let ctx = Context { now: frozen_now() };
+        assert!(command.execute(&ctx).await.is_ok());
     }
 
     #[tokio::test]
     async fn test_schedule_command_with_timezone() {
-        let command = ScheduleCommand::new(None, 2, Some("UTC".to_string()));
-        assert!(command.execute().await.is_ok());
+        let command = ScheduleCommand::new(None, 2, Some("UTC".to_string()), Format::Table, None);
+        let ctx = Context { now: frozen_now() };
+        assert!(command.execute(&ctx).await.is_ok());
     }
 
     #[test]
     fn test_get_timezone() {
-        let command = ScheduleCommand::new(None, 2, None);
-        let tz = command.get_timezone();
+        let now = frozen_now();
+
+        let command = ScheduleCommand::new(None, 2, None, Format::Table, None);
+        let tz = command.get_timezone(now);
         assert!(tz.utc_minus_local() >= -14 * 3600 && tz.utc_minus_local() <= 14 * 3600);
 
-        let command = ScheduleCommand::new(None, 2, Some("UTC".to_string()));
-        let tz = command.get_timezone();
+        let command = ScheduleCommand::new(None, 2, Some("UTC".to_string()), Format::Table, None);
+        let tz = command.get_timezone(now);
         assert_eq!(tz.utc_minus_local(), 0);
 
-        let command = ScheduleCommand::new(None, 2, Some("IST".to_string()));
-        let tz = command.get_timezone();
+        let command = ScheduleCommand::new(None, 2, Some("IST".to_string()), Format::Table, None);
+        let tz = command.get_timezone(now);
         assert_eq!(tz.utc_minus_local(), -(5 * 3600 + 30 * 60));
     }
 
+    #[test]
+    fn test_get_timezone_iana_dst() {
+        // July is EDT (UTC-4); January is EST (UTC-5).
+        let command = ScheduleCommand::new(None, 2, Some("America/New_York".to_string()), Format::Table, None);
+
+        let summer = Utc.with_ymd_and_hms(2024, 7, 1, 12, 0, 0).unwrap();
+        assert_eq!(command.get_timezone(summer).utc_minus_local(), 4 * 3600);
+
+        let winter = Utc.with_ymd_and_hms(2024, 1, 1, 12, 0, 0).unwrap();
+        assert_eq!(command.get_timezone(winter).utc_minus_local(), 5 * 3600);
+    }
+
     #[test]
     fn test_get_target_day() {
-        let command = ScheduleCommand::new(None, 2, None);
-        let day = command.get_target_day();
-        assert!(day < 7);
+        let now = frozen_now();
 
-        let command = ScheduleCommand::new(Some("monday".to_string()), 2, None);
-        assert_eq!(command.get_target_day(), 0);
+        let command = ScheduleCommand::new(None, 2, None, Format::Table, None);
+        assert_eq!(command.get_target_day(now), 0); // frozen_now() is a Monday
+
+        let command = ScheduleCommand::new(Some("monday".to_string()), 2, None, Format::Table, None);
+        assert_eq!(command.get_target_day(now), 0);
+
+        let command = ScheduleCommand::new(Some("wednesday".to_string()), 2, None, Format::Table, None);
+        assert_eq!(command.get_target_day(now), 2);
     }
 
     #[test]
     fn test_get_time_range() {
-        let command = ScheduleCommand::new(None, 2, None);
-        let (start, end) = command.get_time_range();
-        assert!(end - start == 2 * 24 * 3600);
+        let now = frozen_now();
+
+        let command = ScheduleCommand::new(None, 2, Some("UTC".to_string()), Format::Table, None);
+        let (start, end) = command.get_time_range(now);
+        assert_eq!(start, now.timestamp());
+        assert_eq!(end - start, 2 * 24 * 3600);
+
+        let command = ScheduleCommand::new(Some("wednesday".to_string()), 2, Some("UTC".to_string()), Format::Table, None);
+        let (start, end) = command.get_time_range(now);
+        assert_eq!(start, now.timestamp() + 2 * 24 * 3600);
+        assert_eq!(end - start, 2 * 24 * 3600);
+    }
+
+    #[test]
+    fn test_get_time_range_cet_abbreviation_matches_get_timezone_offset() {
+        // "CET" is in the abbreviation table as a permanent +01:00, but
+        // `chrono-tz` also parses "CET" as a real, DST-observing zone. In
+        // CEST season (summer) those two disagree by an hour; the query
+        // window built by `get_time_range` must use the same +01:00 offset
+        // that `get_timezone` uses to render the rows, not chrono-tz's
+        // DST-aware +02:00.
+        let summer = Utc.with_ymd_and_hms(2024, 7, 3, 12, 0, 0).unwrap(); // a Wednesday
+
+        let command = ScheduleCommand::new(None, 1, Some("CET".to_string()), Format::Table, None);
+        let (start, _end) = command.get_time_range(summer);
+        let display_offset = command.get_timezone(summer);
+
+        assert_eq!(display_offset.utc_minus_local(), -3600); // fixed +01:00
+        assert_eq!(start, summer.with_timezone(&display_offset).timestamp());
     }
 
     #[test]
     fn test_format_relative_time() {
-        let command = ScheduleCommand::new(None, 2, None);
-        let now = Utc::now().timestamp();
-        
+        let command = ScheduleCommand::new(None, 2, None, Format::Table, None);
+        let now = frozen_now();
+        let now_ts = now.timestamp();
+
         // Test past times
-        assert!(command.format_relative_time(now - 3600).contains("1h ago"));
-        assert!(command.format_relative_time(now - 86400).contains("1d ago"));
-        
+        assert_eq!(command.format_relative_time(now_ts - 3600, now), "1h ago");
+        assert_eq!(command.format_relative_time(now_ts - 86400, now), "1d ago");
+
         // Test future times
-        assert!(command.format_relative_time(now + 3600).contains("in 1h"));
-        assert!(command.format_relative_time(now + 86400).contains("in 1d"));
+        assert_eq!(command.format_relative_time(now_ts + 3600, now), "in 1h");
+        assert_eq!(command.format_relative_time(now_ts + 86400, now), "in 1d");
     }
-} 
\ No newline at end of file
+}
@@ -0,0 +1,256 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::{Datelike, DateTime, Duration, FixedOffset, TimeZone, Utc};
+use prettytable::{color, Row};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::io::{stdout, IsTerminal};
+
+use crate::{
+    api::AniListClient,
+    commands::{Command, Context},
+    display::{create_table, format_datetime, styled_cell},
+    utils::{
+        get_user_timezone, local_to_utc_checked, match_iana_timezone, match_timezone,
+        parse_day_of_week, resolve_offset_at,
+    },
+};
+
+const DAY_NAMES: [&str; 7] = [
+    "Monday",
+    "Tuesday",
+    "Wednesday",
+    "Thursday",
+    "Friday",
+    "Saturday",
+    "Sunday",
+];
+
+/// Command to show the full airing week, grouped by day
+pub struct WeekCommand {
+    timezone: Option<String>,
+    week_start: u32,
+    client: AniListClient,
+}
+
+impl WeekCommand {
+    /// Create a new week command
+    pub fn new(timezone: Option<String>, week_start: Option<String>) -> Self {
+        let week_start = week_start
+            .as_deref()
+            .and_then(parse_day_of_week)
+            .unwrap_or(0);
+
+        Self {
+            timezone,
+            week_start,
+            client: AniListClient::new(),
+        }
+    }
+
+    /// Get the timezone to use for display, resolved to the offset in
+    /// effect at `now`
+    fn get_timezone(&self, now: DateTime<Utc>) -> FixedOffset {
+        if let Some(tz) = &self.timezone {
+            resolve_offset_at(tz, now).unwrap_or_else(|| {
+                eprintln!("Invalid timezone: {}. Using default timezone.", tz);
+                get_user_timezone()
+            })
+        } else {
+            get_user_timezone()
+        }
+    }
+
+    /// Get the UTC timestamp range for the week (starting on `week_start`)
+    /// that contains `now`
+    ///
+    /// A 7-day window is the case most likely to straddle a DST transition,
+    /// so this follows the same split `ScheduleCommand::get_time_range`
+    /// uses: a real IANA zone builds the week-start boundary from a local
+    /// wall-clock time and converts it back to UTC via
+    /// [`local_to_utc_checked`] (handling the DST gap/overlap), while
+    /// abbreviations and the default timezone keep the simpler fixed-offset
+    /// arithmetic. The IANA branch is only taken when `self.timezone`
+    /// doesn't match the abbreviation table, so this can never diverge from
+    /// [`get_timezone`]'s precedence.
+    fn get_week_range(&self, now: DateTime<Utc>) -> (i64, i64) {
+        let iana_zone = self
+            .timezone
+            .as_deref()
+            .filter(|tz| match_timezone(tz).is_none())
+            .and_then(match_iana_timezone);
+
+        let start = if let Some(zone) = iana_zone {
+            let now_local = now.with_timezone(&zone);
+            let current_day = now_local.weekday().num_days_from_monday();
+            let days_since_start = (current_day + 7 - self.week_start) % 7;
+
+            let start_date = now_local.date_naive() - Duration::days(days_since_start as i64);
+            local_to_utc_checked(start_date.and_time(now_local.time()), &zone).timestamp()
+        } else {
+            let timezone = self.get_timezone(now);
+            let now_local = now.with_timezone(&timezone);
+            let current_day = now_local.weekday().num_days_from_monday();
+            let days_since_start = (current_day + 7 - self.week_start) % 7;
+
+            now_local.timestamp() - (days_since_start as i64) * 24 * 3600
+        };
+
+        let end = start + 7 * 24 * 3600;
+
+        (start, end)
+    }
+}
+
+#[async_trait]
+impl Command for WeekCommand {
+    async fn execute(&self, ctx: &Context) -> Result<()> {
+        let timezone = self.get_timezone(ctx.now);
+        let (start, end) = self.get_week_range(ctx.now);
+        // Decided once, by the caller that owns the sink below, rather than
+        // re-queried from inside the per-day print loop.
+        let is_tty = stdout().is_terminal();
+
+        // GraphQL query for the whole week's airing schedule
+        let query = r#"
+            query ($start: Int, $end: Int) {
+                Page(perPage: 50) {
+                    airingSchedules(airingAt_greater: $start, airingAt_lesser: $end) {
+                        airingAt
+                        episode
+                        media {
+                            title {
+                                romaji
+                                english
+                            }
+                        }
+                    }
+                }
+            }
+        "#;
+
+        let variables = serde_json::json!({
+            "start": start,
+            "end": end,
+        });
+
+        let response: Value = self.client.query(query, variables).await?;
+        let schedules = response["data"]["Page"]["airingSchedules"].as_array().unwrap();
+
+        let mut by_day: BTreeMap<u32, Vec<(String, i64, i64)>> = BTreeMap::new();
+        for schedule in schedules {
+            let title = schedule["media"]["title"]["english"]
+                .as_str()
+                .or(schedule["media"]["title"]["romaji"].as_str())
+                .unwrap_or("Unknown Title");
+
+            let episode: i64 = schedule["episode"].as_i64().unwrap_or(0);
+            let airing_at: i64 = schedule["airingAt"].as_i64().unwrap_or(0);
+
+            let local_time = Utc.timestamp_opt(airing_at, 0).unwrap().with_timezone(&timezone);
+            let day = local_time.weekday().num_days_from_monday();
+
+            by_day
+                .entry(day)
+                .or_default()
+                .push((title.to_string(), episode, airing_at));
+        }
+
+        for offset in 0..7 {
+            let day = (self.week_start + offset) % 7;
+            println!("{}", DAY_NAMES[day as usize]);
+
+            let mut table = create_table(&["Title", "Episode", "Time"]);
+            if let Some(entries) = by_day.get(&day) {
+                for (title, episode, airing_at) in entries {
+                    let airing_time = Utc.timestamp_opt(*airing_at, 0).unwrap();
+                    table.add_row(Row::new(vec![
+                        styled_cell(title, color::CYAN),
+                        styled_cell(&episode.to_string(), color::YELLOW),
+                        styled_cell(&format_datetime(airing_time, timezone), color::GREEN),
+                    ]));
+                }
+            }
+            if is_tty {
+                table.print_tty(false)?;
+            } else {
+                table.print(&mut stdout())?;
+            }
+            println!();
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn frozen_now() -> DateTime<Utc> {
+        // Wednesday, 2024-01-03 12:00:00 UTC
+        Utc.with_ymd_and_hms(2024, 1, 3, 12, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn test_get_week_range_default_monday_start() {
+        let command = WeekCommand::new(Some("UTC".to_string()), None);
+        let now = frozen_now();
+        let (start, end) = command.get_week_range(now);
+
+        assert_eq!(start, now.timestamp() - 2 * 24 * 3600); // back to Monday
+        assert_eq!(end - start, 7 * 24 * 3600);
+    }
+
+    #[test]
+    fn test_get_week_range_custom_start() {
+        let command = WeekCommand::new(Some("UTC".to_string()), Some("wednesday".to_string()));
+        let now = frozen_now();
+        let (start, end) = command.get_week_range(now);
+
+        assert_eq!(start, now.timestamp()); // frozen_now() is already Wednesday
+        assert_eq!(end - start, 7 * 24 * 3600);
+    }
+
+    #[test]
+    fn test_get_week_range_handles_dst_gap_at_week_boundary() {
+        let command = WeekCommand::new(Some("America/New_York".to_string()), Some("sunday".to_string()));
+        // Wednesday 2024-03-13 02:30 EDT; the Sunday week boundary lands on
+        // 2024-03-10, inside the spring-forward gap (02:00-03:00 doesn't
+        // exist that day).
+        let now = Utc.with_ymd_and_hms(2024, 3, 13, 6, 30, 0).unwrap();
+
+        let (start, end) = command.get_week_range(now);
+
+        // local_to_utc_checked should round forward to 03:00 EDT (07:00
+        // UTC) instead of producing a bogus instant for the nonexistent
+        // 02:30 local time.
+        let expected = Utc.with_ymd_and_hms(2024, 3, 10, 7, 0, 0).unwrap();
+        assert_eq!(start, expected.timestamp());
+        assert_eq!(end - start, 7 * 24 * 3600);
+    }
+
+    #[test]
+    fn test_get_week_range_cet_abbreviation_matches_get_timezone_offset() {
+        // Same precedence guarantee as schedule.rs: "CET" must resolve to
+        // the same fixed +01:00 offset here as it does in `get_timezone`,
+        // not chrono-tz's DST-aware zone of the same name.
+        let command = WeekCommand::new(Some("CET".to_string()), None);
+        let summer = Utc.with_ymd_and_hms(2024, 7, 3, 12, 0, 0).unwrap(); // a Wednesday
+
+        let (start, _end) = command.get_week_range(summer);
+        let display_offset = command.get_timezone(summer);
+
+        assert_eq!(display_offset.utc_minus_local(), -3600); // fixed +01:00
+        let days_since_monday = summer.with_timezone(&display_offset).weekday().num_days_from_monday();
+        let expected = summer.with_timezone(&display_offset).timestamp() - (days_since_monday as i64) * 24 * 3600;
+        assert_eq!(start, expected);
+    }
+
+    #[tokio::test]
+    async fn test_week_command_execute() {
+        let command = WeekCommand::new(Some("UTC".to_string()), None);
+        let ctx = Context { now: frozen_now() };
+        assert!(command.execute(&ctx).await.is_ok());
+    }
+}
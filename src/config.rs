@@ -0,0 +1,157 @@
+// src/config.rs
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context as _, Result};
+use serde::Deserialize;
+
+use crate::display::Format;
+
+/// User-configurable defaults, loaded from a TOML file so common flags
+/// (`-t`, `-f`, `-s`) don't need to be repeated on every invocation.
+/// Any field the user omits on the command line falls back to this config,
+/// and any field the config omits falls back to the existing hard-coded
+/// defaults (system timezone, table format, Monday week start).
+#[derive(Debug, Default, Deserialize)]
+pub struct Config {
+    pub timezone: Option<String>,
+    pub default_format: Option<Format>,
+    pub week_start: Option<String>,
+}
+
+impl Config {
+    /// Load the config file at `path`, or from the default config
+    /// directory if `path` is `None`. A missing file resolves to
+    /// `Config::default()` rather than erroring, since having no config
+    /// file is the common case.
+    pub fn load(path: Option<&Path>) -> Result<Self> {
+        let resolved = match path {
+            Some(path) => Some(path.to_path_buf()),
+            None => Self::default_path(),
+        };
+
+        let Some(resolved) = resolved else {
+            return Ok(Self::default());
+        };
+
+        if !resolved.exists() {
+            return Ok(Self::default());
+        }
+
+        let contents = fs::read_to_string(&resolved)
+            .with_context(|| format!("Failed to read config file at {}", resolved.display()))?;
+
+        toml::from_str(&contents)
+            .with_context(|| format!("Failed to parse config file at {}", resolved.display()))
+    }
+
+    /// Default config file location: `<config dir>/animesh/config.toml`
+    pub fn default_path() -> Option<PathBuf> {
+        dirs::config_dir().map(|dir| dir.join("animesh").join("config.toml"))
+    }
+
+    /// Resolve the timezone to use: an explicit CLI flag wins, then the
+    /// config file; `None` means fall back to the user's system timezone.
+    pub fn resolve_timezone(&self, cli_value: Option<String>) -> Option<String> {
+        cli_value.or_else(|| self.timezone.clone())
+    }
+
+    /// Resolve the output format to use: an explicit CLI flag wins, then
+    /// the config file, then [`Format::default`].
+    pub fn resolve_format(&self, cli_value: Option<Format>) -> Format {
+        cli_value.or(self.default_format).unwrap_or_default()
+    }
+
+    /// Resolve the week-start day to use: an explicit CLI flag wins, then
+    /// the config file; `None` means fall back to Monday.
+    pub fn resolve_week_start(&self, cli_value: Option<String>) -> Option<String> {
+        cli_value.or_else(|| self.week_start.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Write `contents` to a fresh file under the system temp dir, unique
+    /// to this test invocation so parallel tests don't collide, and return
+    /// its path.
+    fn write_temp_file(name: &str, contents: &str) -> PathBuf {
+        let path = std::env::temp_dir().join(format!("animesh-config-test-{name}-{}.toml", std::process::id()));
+        fs::write(&path, contents).unwrap();
+        path
+    }
+
+    #[test]
+    fn test_load_missing_file_returns_defaults() {
+        let path = std::env::temp_dir().join("animesh-config-test-does-not-exist.toml");
+        let config = Config::load(Some(&path)).unwrap();
+
+        assert!(config.timezone.is_none());
+        assert!(config.default_format.is_none());
+        assert!(config.week_start.is_none());
+    }
+
+    #[test]
+    fn test_load_malformed_toml_errors() {
+        let path = write_temp_file("malformed", "timezone = [this isn't valid toml");
+        let result = Config::load(Some(&path));
+        fs::remove_file(&path).unwrap();
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_load_parses_valid_toml() {
+        let path = write_temp_file(
+            "valid",
+            r#"
+                timezone = "IST"
+                default_format = "json"
+                week_start = "sunday"
+            "#,
+        );
+        let config = Config::load(Some(&path)).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(config.timezone.as_deref(), Some("IST"));
+        assert!(matches!(config.default_format, Some(Format::Json)));
+        assert_eq!(config.week_start.as_deref(), Some("sunday"));
+    }
+
+    #[test]
+    fn test_resolve_timezone_precedence() {
+        let config = Config {
+            timezone: Some("IST".to_string()),
+            ..Config::default()
+        };
+
+        assert_eq!(config.resolve_timezone(Some("UTC".to_string())), Some("UTC".to_string()));
+        assert_eq!(config.resolve_timezone(None), Some("IST".to_string()));
+        assert_eq!(Config::default().resolve_timezone(None), None);
+    }
+
+    #[test]
+    fn test_resolve_format_precedence() {
+        let config = Config {
+            default_format: Some(Format::Csv),
+            ..Config::default()
+        };
+
+        assert!(matches!(config.resolve_format(Some(Format::Json)), Format::Json));
+        assert!(matches!(config.resolve_format(None), Format::Csv));
+        assert!(matches!(Config::default().resolve_format(None), Format::Table));
+    }
+
+    #[test]
+    fn test_resolve_week_start_precedence() {
+        let config = Config {
+            week_start: Some("sunday".to_string()),
+            ..Config::default()
+        };
+
+        assert_eq!(config.resolve_week_start(Some("friday".to_string())), Some("friday".to_string()));
+        assert_eq!(config.resolve_week_start(None), Some("sunday".to_string()));
+        assert_eq!(Config::default().resolve_week_start(None), None);
+    }
+}
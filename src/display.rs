@@ -0,0 +1,262 @@
+// src/display.rs
+use std::collections::BTreeMap;
+use std::io::Write;
+
+use anyhow::Result;
+use chrono::{DateTime, FixedOffset, NaiveDate, Utc};
+use clap::ValueEnum;
+use prettytable::{color, Attr, Cell, Row, Table};
+use serde::Deserialize;
+
+/// Create an empty table styled with bold cyan headers
+pub fn create_table(headers: &[&str]) -> Table {
+    let mut table = Table::new();
+    table.add_row(Row::new(
+        headers
+            .iter()
+            .map(|h| styled_cell(h, color::BRIGHT_CYAN))
+            .collect(),
+    ));
+    table
+}
+
+/// Build a colored, bold table cell
+pub fn styled_cell(text: &str, color: color::Color) -> Cell {
+    Cell::new(text)
+        .with_style(Attr::ForegroundColor(color))
+        .with_style(Attr::Bold)
+}
+
+/// Format a UTC instant in the given fixed-offset timezone
+pub fn format_datetime(datetime: DateTime<Utc>, timezone: FixedOffset) -> String {
+    datetime
+        .with_timezone(&timezone)
+        .format("%Y-%m-%d %H:%M")
+        .to_string()
+}
+
+/// A single parsed airing-schedule entry, decoupled from the raw API response
+/// so it can be handed to any [`Formatter`].
+pub struct ScheduleRow {
+    pub title: String,
+    pub episode: i64,
+    pub date: NaiveDate,
+    pub time: String,
+    pub relative: String,
+    pub is_past: bool,
+}
+
+/// Output format selectable via `--format` / `-f`, or `default_format` in
+/// the config file
+#[derive(Clone, Copy, Debug, Default, ValueEnum, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Format {
+    #[default]
+    Table,
+    Json,
+    Csv,
+    Chart,
+}
+
+impl Format {
+    /// Resolve this format to its concrete [`Formatter`] implementation
+    pub fn formatter(&self) -> Box<dyn Formatter> {
+        match self {
+            Format::Table => Box::new(TableFormatter),
+            Format::Json => Box::new(JsonFormatter),
+            Format::Csv => Box::new(CsvFormatter),
+            Format::Chart => Box::new(ChartFormatter),
+        }
+    }
+}
+
+/// Renders parsed airing-schedule rows to a `Write` sink
+///
+/// `is_tty` tells the formatter whether `out` is the process's real,
+/// interactive stdout, as judged by whoever constructed `out` — never by
+/// querying the live process stdout from inside an implementation, since
+/// an implementation has no way to confirm `out` actually *is* that stream.
+pub trait Formatter {
+    fn write(&self, rows: &[ScheduleRow], tz_label: &str, out: &mut dyn Write, is_tty: bool) -> Result<()>;
+}
+
+/// Renders rows as the classic `prettytable` grid
+///
+/// `prettytable`'s generic `Table::print(&mut dyn Write)` never emits ANSI
+/// colour codes, regardless of styling, so the colours applied via
+/// [`styled_cell`] would otherwise be silently dropped. `Table::print_tty`
+/// does emit them, but it always writes straight to the process's real
+/// `io::stdout()` and ignores `out` entirely, so it's only correct to call
+/// when the caller has confirmed `out` actually is that stream — hence
+/// `is_tty` is passed in rather than queried here.
+pub struct TableFormatter;
+
+impl Formatter for TableFormatter {
+    fn write(&self, rows: &[ScheduleRow], tz_label: &str, out: &mut dyn Write, is_tty: bool) -> Result<()> {
+        let mut table = create_table(&[&format!("Schedule ({})", tz_label), "Episode", "Time", "Status"]);
+
+        for row in rows {
+            table.add_row(Row::new(vec![
+                styled_cell(&row.title, color::CYAN),
+                styled_cell(&row.episode.to_string(), color::YELLOW),
+                styled_cell(&row.time, color::GREEN),
+                styled_cell(&row.relative, if row.is_past { color::RED } else { color::BLUE }),
+            ]));
+        }
+
+        if is_tty {
+            table.print_tty(false)?;
+        } else {
+            table.print(out)?;
+        }
+        Ok(())
+    }
+}
+
+/// Renders rows as a JSON array
+pub struct JsonFormatter;
+
+impl Formatter for JsonFormatter {
+    fn write(&self, rows: &[ScheduleRow], _tz_label: &str, out: &mut dyn Write, _is_tty: bool) -> Result<()> {
+        let json: Vec<_> = rows
+            .iter()
+            .map(|row| {
+                serde_json::json!({
+                    "title": row.title,
+                    "episode": row.episode,
+                    "time": row.time,
+                    "relative": row.relative,
+                })
+            })
+            .collect();
+
+        serde_json::to_writer_pretty(&mut *out, &json)?;
+        writeln!(out)?;
+        Ok(())
+    }
+}
+
+/// Renders rows as CSV (`title,episode,time,relative`)
+pub struct CsvFormatter;
+
+impl Formatter for CsvFormatter {
+    fn write(&self, rows: &[ScheduleRow], _tz_label: &str, out: &mut dyn Write, _is_tty: bool) -> Result<()> {
+        writeln!(out, "title,episode,time,relative")?;
+        for row in rows {
+            writeln!(
+                out,
+                "{},{},{},{}",
+                csv_escape(&row.title),
+                row.episode,
+                csv_escape(&row.time),
+                csv_escape(&row.relative)
+            )?;
+        }
+        Ok(())
+    }
+}
+
+fn csv_escape(field: &str) -> String {
+    if field.contains(',') || field.contains('"') {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// Renders rows as an ASCII bar chart of episode counts per day
+pub struct ChartFormatter;
+
+impl Formatter for ChartFormatter {
+    fn write(&self, rows: &[ScheduleRow], _tz_label: &str, out: &mut dyn Write, _is_tty: bool) -> Result<()> {
+        let mut per_day: BTreeMap<NaiveDate, usize> = BTreeMap::new();
+        for row in rows {
+            *per_day.entry(row.date).or_insert(0) += 1;
+        }
+
+        for (date, count) in per_day {
+            let bar = "#".repeat(count);
+            writeln!(out, "{} | {:<2} {}", date.format("%Y-%m-%d"), count, bar)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+
+    fn rows() -> Vec<ScheduleRow> {
+        vec![
+            ScheduleRow {
+                title: "Frieren".to_string(),
+                episode: 5,
+                date: NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(),
+                time: "2024-01-01 10:00".to_string(),
+                relative: "in 2h".to_string(),
+                is_past: false,
+            },
+            ScheduleRow {
+                title: "One Piece, the".to_string(),
+                episode: 1090,
+                date: NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(),
+                time: "2024-01-01 18:00".to_string(),
+                relative: "2h ago".to_string(),
+                is_past: true,
+            },
+        ]
+    }
+
+    #[test]
+    fn test_table_formatter_writes_titles() {
+        let mut out = Vec::new();
+        // is_tty: false so this exercises `table.print(out)` deterministically,
+        // regardless of whether the test runner's own stdout is a terminal.
+        TableFormatter.write(&rows(), "UTC", &mut out, false).unwrap();
+        let text = String::from_utf8(out).unwrap();
+
+        assert!(text.contains("Frieren"));
+        assert!(text.contains("One Piece, the"));
+        assert!(text.contains("Schedule (UTC)"));
+    }
+
+    #[test]
+    fn test_json_formatter() {
+        let mut out = Vec::new();
+        JsonFormatter.write(&rows(), "UTC", &mut out, false).unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&out).unwrap();
+
+        assert_eq!(json[0]["title"], "Frieren");
+        assert_eq!(json[0]["episode"], 5);
+        assert_eq!(json[1]["relative"], "2h ago");
+    }
+
+    #[test]
+    fn test_csv_formatter_escapes_commas_and_quotes() {
+        let mut out = Vec::new();
+        CsvFormatter.write(&rows(), "UTC", &mut out, false).unwrap();
+        let text = String::from_utf8(out).unwrap();
+        let mut lines = text.lines();
+
+        assert_eq!(lines.next().unwrap(), "title,episode,time,relative");
+        assert_eq!(lines.next().unwrap(), "Frieren,5,2024-01-01 10:00,in 2h");
+        assert_eq!(lines.next().unwrap(), "\"One Piece, the\",1090,2024-01-01 18:00,2h ago");
+    }
+
+    #[test]
+    fn test_csv_escape() {
+        assert_eq!(csv_escape("plain"), "plain");
+        assert_eq!(csv_escape("has,comma"), "\"has,comma\"");
+        assert_eq!(csv_escape("has\"quote"), "\"has\"\"quote\"");
+    }
+
+    #[test]
+    fn test_chart_formatter_buckets_by_day() {
+        let mut out = Vec::new();
+        ChartFormatter.write(&rows(), "UTC", &mut out, false).unwrap();
+        let text = String::from_utf8(out).unwrap();
+
+        assert_eq!(text, "2024-01-01 | 2  ##\n");
+    }
+}
@@ -1,20 +1,30 @@
 // src/main.rs
 mod api;
 mod commands;
+mod config;
 mod display;
 mod utils;
 
+use std::path::PathBuf;
+
 use clap::{Parser, Subcommand};
 use commands::{
-    Command, ScheduleCommand,
+    Command, Context, ScheduleCommand, WeekCommand,
 };
+use config::Config;
+use display::Format;
 use anyhow::Result;
+use regex::Regex;
 use tokio;
 
 /// A powerful CLI tool for anime fans to track their favorite shows
 #[derive(Parser)]
 #[command(name = "animesh", author = "Abhishek Gautam", version = "0.1.0", about = "Track anime schedules and discover new shows", long_about = None)]
 pub struct Cli {
+    /// Path to a config file (default: the platform config dir)
+    #[arg(long = "config", global = true)]
+    config: Option<PathBuf>,
+
     #[command(subcommand)]
     command: Commands,
 }
@@ -34,20 +44,73 @@ pub enum Commands {
         /// Timezone to show schedule in (e.g., UTC, IST, JST)
         #[arg(short = 't', long = "timezone")]
         timezone: Option<String>,
+
+        /// Output format
+        #[arg(short = 'f', long = "format", value_enum)]
+        format: Option<Format>,
+
+        /// Only show anime whose title matches this regex pattern
+        #[arg(short = 'g', long = "grep")]
+        grep: Option<String>,
+    },
+
+    /// View the full airing week, grouped by day
+    Week {
+        /// Timezone to show schedule in (e.g., UTC, IST, JST)
+        #[arg(short = 't', long = "timezone")]
+        timezone: Option<String>,
+
+        /// Day the week should start on (default: monday)
+        #[arg(short = 's', long = "week-start")]
+        week_start: Option<String>,
     },
+
+    /// Print the resolved configuration (config file, merged with defaults)
+    Config,
 }
 
 #[tokio::main]
 async fn main() -> Result<()> {
     let cli = Cli::parse();
+    let config = Config::load(cli.config.as_deref())?;
 
     match &cli.command {
-        Commands::Schedule { day, interval, timezone } => {
-            ScheduleCommand::new(day.clone(), *interval, timezone.clone())
-                .execute()
+        Commands::Schedule { day, interval, timezone, format, grep } => {
+            let grep = grep.as_deref().map(Regex::new).transpose()?;
+            let ctx = Context::now();
+
+            let timezone = config.resolve_timezone(timezone.clone());
+            let format = config.resolve_format(*format);
+
+            ScheduleCommand::new(day.clone(), *interval, timezone, format, grep)
+                .execute(&ctx)
                 .await
                 .expect("Failed to execute schedule command");
         }
+        Commands::Week { timezone, week_start } => {
+            let ctx = Context::now();
+
+            let timezone = config.resolve_timezone(timezone.clone());
+            let week_start = config.resolve_week_start(week_start.clone());
+
+            WeekCommand::new(timezone, week_start)
+                .execute(&ctx)
+                .await
+                .expect("Failed to execute week command");
+        }
+        Commands::Config => {
+            let path = cli.config.clone().or_else(Config::default_path);
+
+            if let Some(path) = &path {
+                println!("config file: {}", path.display());
+            } else {
+                println!("config file: (none; no config directory available)");
+            }
+
+            println!("timezone: {}", config.timezone.as_deref().unwrap_or("(system default)"));
+            println!("default_format: {:?}", config.resolve_format(None));
+            println!("week_start: {}", config.week_start.as_deref().unwrap_or("monday"));
+        }
     }
     Ok(())
 }
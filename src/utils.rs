@@ -0,0 +1,149 @@
+// src/utils.rs
+use chrono::{DateTime, FixedOffset, LocalResult, NaiveDateTime, Offset, TimeZone, Utc};
+use chrono_tz::Tz;
+
+/// Resolve a common timezone abbreviation to a fixed UTC offset
+pub fn match_timezone(tz: &str) -> Option<FixedOffset> {
+    match tz.to_uppercase().as_str() {
+        "UTC" | "GMT" => FixedOffset::east_opt(0),
+        "IST" => FixedOffset::east_opt(5 * 3600 + 30 * 60),
+        "JST" => FixedOffset::east_opt(9 * 3600),
+        "EST" => FixedOffset::west_opt(5 * 3600),
+        "PST" => FixedOffset::west_opt(8 * 3600),
+        "CST" => FixedOffset::west_opt(6 * 3600),
+        "CET" => FixedOffset::east_opt(3600),
+        _ => None,
+    }
+}
+
+/// Resolve an IANA zone name (e.g. "America/New_York", "Asia/Kolkata") to a
+/// `chrono-tz` zone. Unlike [`match_timezone`], this knows each zone's DST
+/// rules, so the offset it implies varies with the date it's applied to.
+pub fn match_iana_timezone(tz: &str) -> Option<Tz> {
+    tz.parse().ok()
+}
+
+/// Resolve `tz` to the `FixedOffset` in effect at `at`, trying the fixed
+/// abbreviation table first and falling back to a DST-aware IANA zone
+/// lookup so zones like "Europe/London" resolve to the correct offset for
+/// the date in question rather than only ever UTC+0.
+pub fn resolve_offset_at(tz: &str, at: DateTime<Utc>) -> Option<FixedOffset> {
+    match_timezone(tz).or_else(|| {
+        match_iana_timezone(tz).map(|zone| zone.offset_from_utc_datetime(&at.naive_utc()).fix())
+    })
+}
+
+/// Get the user's local timezone as a fixed offset
+pub fn get_user_timezone() -> FixedOffset {
+    *chrono::Local::now().offset()
+}
+
+/// Convert a naive local date/time in `tz` to a UTC instant, correctly
+/// handling the DST gap (spring-forward) and overlap (fall-back) cases that
+/// a plain `TimeZone::from_local_datetime` call would otherwise leave the
+/// caller to sort out via `LocalResult`.
+///
+/// - A nonexistent local time (spring-forward gap) has no valid instant;
+///   this warns on stderr and advances minute-by-minute to the first valid
+///   instant after the gap.
+/// - An ambiguous local time (fall-back overlap) has two valid instants;
+///   this warns on stderr and picks the earlier one.
+pub fn local_to_utc_checked(naive: NaiveDateTime, tz: &Tz) -> DateTime<Utc> {
+    match tz.from_local_datetime(&naive) {
+        LocalResult::Single(dt) => dt.with_timezone(&Utc),
+        LocalResult::Ambiguous(earlier, _later) => {
+            eprintln!(
+                "Warning: {naive} is ambiguous in {tz} (DST fall-back); using the earlier instant"
+            );
+            earlier.with_timezone(&Utc)
+        }
+        LocalResult::None => {
+            let mut candidate = naive;
+            loop {
+                candidate += chrono::Duration::minutes(1);
+                if let LocalResult::Single(dt) = tz.from_local_datetime(&candidate) {
+                    eprintln!(
+                        "Warning: {naive} does not exist in {tz} (DST spring-forward gap); using {dt} instead"
+                    );
+                    break dt.with_timezone(&Utc);
+                }
+            }
+        }
+    }
+}
+
+/// Parse a weekday name into a `chrono` day-of-week index (Monday = 0)
+pub fn parse_day_of_week(day: &str) -> Option<u32> {
+    match day.to_lowercase().as_str() {
+        "monday" | "mon" => Some(0),
+        "tuesday" | "tue" => Some(1),
+        "wednesday" | "wed" => Some(2),
+        "thursday" | "thu" => Some(3),
+        "friday" | "fri" => Some(4),
+        "saturday" | "sat" => Some(5),
+        "sunday" | "sun" => Some(6),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+
+    #[test]
+    fn test_match_iana_timezone() {
+        assert!(match_iana_timezone("America/New_York").is_some());
+        assert!(match_iana_timezone("Not/AZone").is_none());
+    }
+
+    #[test]
+    fn test_resolve_offset_at_prefers_abbreviation() {
+        let at = Utc.with_ymd_and_hms(2024, 6, 1, 0, 0, 0).unwrap();
+        assert_eq!(resolve_offset_at("IST", at).unwrap(), match_timezone("IST").unwrap());
+    }
+
+    #[test]
+    fn test_resolve_offset_at_iana_dst() {
+        let winter = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let summer = Utc.with_ymd_and_hms(2024, 7, 1, 0, 0, 0).unwrap();
+
+        let winter_offset = resolve_offset_at("America/New_York", winter).unwrap();
+        let summer_offset = resolve_offset_at("America/New_York", summer).unwrap();
+
+        // EST (UTC-5) in winter, EDT (UTC-4) in summer
+        assert_eq!(winter_offset.utc_minus_local(), 5 * 3600);
+        assert_eq!(summer_offset.utc_minus_local(), 4 * 3600);
+    }
+
+    #[test]
+    fn test_local_to_utc_checked_nonexistent_time() {
+        // 2024-03-10 02:30:00 America/New_York falls in the spring-forward
+        // gap (clocks jump from 02:00 to 03:00).
+        let tz: Tz = "America/New_York".parse().unwrap();
+        let naive = NaiveDate::from_ymd_opt(2024, 3, 10)
+            .unwrap()
+            .and_hms_opt(2, 30, 0)
+            .unwrap();
+
+        let resolved = local_to_utc_checked(naive, &tz);
+        assert_eq!(resolved.with_timezone(&tz).naive_local(), naive + chrono::Duration::minutes(30));
+    }
+
+    #[test]
+    fn test_local_to_utc_checked_ambiguous_time() {
+        // 2024-11-03 01:30:00 America/New_York occurs twice (fall-back).
+        let tz: Tz = "America/New_York".parse().unwrap();
+        let naive = NaiveDate::from_ymd_opt(2024, 11, 3)
+            .unwrap()
+            .and_hms_opt(1, 30, 0)
+            .unwrap();
+
+        let resolved = local_to_utc_checked(naive, &tz);
+        if let LocalResult::Ambiguous(earlier, _later) = tz.from_local_datetime(&naive) {
+            assert_eq!(resolved, earlier.with_timezone(&Utc));
+        } else {
+            panic!("expected an ambiguous local time");
+        }
+    }
+}